@@ -10,8 +10,8 @@ let mut data = Cursor::new(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
 let mut transformed = ReadTransformer::new(
 	&mut data,
 	5,
-	Box::new(|buffer: &mut [u8], _position, _last_attempt| -> Option<(Vec<u8>, usize)> {
-		return Some((
+	Box::new(|buffer: &mut [u8], _position, _last_attempt| -> TransformOutcome {
+		return TransformOutcome::Output(
 			buffer
 				.iter()
 				.map(|x| {
@@ -22,31 +22,90 @@ let mut transformed = ReadTransformer::new(
 				})
 				.collect::<Vec<_>>(),
 			buffer.len(),
-		));
+		);
 	}),
 );
 let mut out = vec![0; 10];
 transformed.read_exact(&mut out).unwrap();
 assert_eq!(out, [1, 0, 3, 0, 5, 0, 7, 0, 9, 0]);
 ```
+
+# `no_std`
+
+The crate builds with `#![no_std]` when the default `std` feature is
+disabled and the `no_std` feature (which pulls in `core_io`) is enabled. In
+that mode `Read`/`Error`/`ErrorKind`/`Result` are pulled from `core_io`
+instead of `std::io`, and the intermediate buffers are backed by
+`alloc::vec::Vec`. Enable it in `Cargo.toml` with:
+
+```toml
+[dependencies.read_transform]
+default-features = false
+features = ["no_std"]
+```
+
+**Known limitation:** `core_io` 0.1.x ships a build script that hardcodes a
+table of rustc commit hashes it recognizes, last refreshed in 2021. It
+panics ("Unknown compiler version, upgrade core_io?") on any current stable
+toolchain, so the `no_std` feature cannot currently be built or tested
+against an up-to-date rustc; treat it as unsupported until `core_io` is
+replaced or patched in via `[patch.crates-io]` with a maintained fork.
 */
 
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(not(feature = "std"))]
+extern crate alloc;
+#[cfg(not(feature = "std"))]
+extern crate core_io;
+
+#[cfg(not(feature = "std"))]
+use alloc::boxed::Box;
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "std")]
 use std::cmp::min;
-use std::io::{Error as IOError, ErrorKind as IOErrorKind, Read, Result as IOResult};
+#[cfg(not(feature = "std"))]
+use core::cmp::min;
+
+#[cfg(feature = "std")]
+use std::io::{
+	BufRead, Error as IOError, ErrorKind as IOErrorKind, Read, Result as IOResult, Seek,
+	SeekFrom, Write,
+};
+#[cfg(not(feature = "std"))]
+use core_io::{Error as IOError, ErrorKind as IOErrorKind, Read, Result as IOResult, Write};
+
+/// Outcome of a single `TransformFn` invocation.
+pub enum TransformOutcome {
+	/// processed bytes are ready. Carries the output vector and the number of bytes consumed
+	/// from the input buffer.
+	Output(Vec<u8>, usize),
+	/// the transform needs more bytes before it can produce output.
+	/// * `NeedMore(None)` asks for one more chunk to be read into the existing buffer, same as
+	///   the old bare `None` return.
+	/// * `NeedMore(Some(len))` asks that the intermediate buffer be grown to at least `len`
+	///   bytes before trying again.
+	NeedMore(Option<usize>),
+}
 
 /// transform function which takes buffer and returns `Vec<u8>` and length of processed bytes
 ///
 /// # Params
 /// * `buffer` - u8 slice to process
 /// * `position` - position (total number of processed bytes)
-/// * `last_attempt` - will be true if EOF is reached, input buffer length is greater than zero, and previous call returned `None`. Indicates that this is last attempt before throwing error.
+/// * `last_attempt` - will be true if EOF is reached, input buffer length is greater than zero, and previous call returned `TransformOutcome::NeedMore`. Indicates that this is last attempt before throwing error.
+///   In `WriteTransformer` it is true on every `flush` that finds buffered bytes, whatever the previous call returned.
 ///
 /// # Return
-/// * function returns `Result` tuple with vector of processed bytes and length of bytes processed in input buffer. If function requires some more bytes to process succesfully it must return `None`.
+/// * function returns a `TransformOutcome`. If function requires some more bytes to process succesfully it must return `TransformOutcome::NeedMore`, optionally requesting a minimum buffer length.
 ///
 /// ### Note about size in the function return
 /// Size in the function return related to the input buffer and not output vector. For example if our function filters even bytes in `[1,2,3,4,5,6]` returned size must be `6` and not `3`.
-pub type TransformFn = Box<FnMut(&mut [u8], usize, bool) -> Option<(Vec<u8>, usize)>>;
+pub type TransformFn = Box<FnMut(&mut [u8], usize, bool) -> TransformOutcome>;
 
 /// Transforms `Read` object with function
 pub struct ReadTransformer<T: Read> {
@@ -56,6 +115,7 @@ pub struct ReadTransformer<T: Read> {
 	read: usize,
 	residue: Vec<u8>,
 	transform: TransformFn,
+	seekable: bool,
 }
 
 impl<T: Read> ReadTransformer<T> {
@@ -73,69 +133,167 @@ impl<T: Read> ReadTransformer<T> {
 			read: 0,
 			residue: vec![],
 			transform: transform_fn,
+			seekable: false,
+		}
+	}
+
+	/// Creates new `ReadTransformer` for a length-preserving transform function (one where every
+	/// output byte corresponds 1:1 to an input byte, e.g. an XOR or other byte-for-byte map), so
+	/// that `seek` can be implemented on top of it when `T: Read + Seek`.
+	///
+	/// # Params
+	/// * `input` - input which will be processed
+	/// * `size` - size of intermediate buffer
+	/// * `transform_fn` - boxed function which acts like a map function. Must produce output of
+	///   the same length as the input it consumes.
+	pub fn new_seekable(input: T, size: usize, transform_fn: TransformFn) -> Self {
+		Self {
+			seekable: true,
+			..Self::new(input, size, transform_fn)
 		}
 	}
 }
 
-impl<T: Read> Read for ReadTransformer<T> {
-	fn read(&mut self, buffer: &mut [u8]) -> IOResult<usize> {
-		if !self.residue.is_empty() {
-			let len = min(self.residue.len(), buffer.len());
-			buffer[..len].copy_from_slice(&self.residue[..len]);
-			self.residue.drain(..len);
-			return Ok(len);
-		};
+impl<T: Read> ReadTransformer<T> {
+	/// Runs the transform pipeline until `self.residue` holds the next chunk of transformed
+	/// output, or input is exhausted (in which case `self.residue` stays empty). Shared by
+	/// `Read::read` and `BufRead::fill_buf` so both pull from the same buffered output.
+	fn fill_residue(&mut self) -> IOResult<()> {
 		loop {
 			let read = self.input.read(&mut self.buffer[self.read..])?;
 			self.read += read;
 			if self.read == 0 {
-				return Ok(0);
+				return Ok(());
 			};
 			let mut res = (self.transform)(&mut self.buffer[..self.read], self.position, false);
-			if res.is_none() && read == 0 {
-				res = (self.transform)(&mut self.buffer[..self.read], self.position, true);
-			}
-			if res.is_none() {
+			if let TransformOutcome::NeedMore(_) = res {
 				if read == 0 {
-					return Err(IOError::new(
-						IOErrorKind::Other,
-						"EOF reached and the length of the buffer is less than transform function accepts to process"
-					));
-				};
-				if self.read == self.buffer.len() {
-					return Err(IOError::new(
-						IOErrorKind::Other,
-						"Intermediate buffer length is less than transform function accepts to process"
-					));
-				};
-				continue;
-			} else {
-				let (mut output, processed) = res.unwrap();
-				if output.is_empty() {
+					res = (self.transform)(&mut self.buffer[..self.read], self.position, true);
+				}
+			}
+			match res {
+				TransformOutcome::NeedMore(requested) => {
+					if read == 0 {
+						return Err(IOError::new(
+							IOErrorKind::Other,
+							"EOF reached and the length of the buffer is less than transform function accepts to process"
+						));
+					};
+					match requested {
+						Some(requested) if requested > self.buffer.len() => {
+							let grown = requested.max(self.buffer.len() * 2);
+							self.buffer.resize(grown, 0);
+						}
+						_ => {
+							if self.read == self.buffer.len() {
+								return Err(IOError::new(
+									IOErrorKind::Other,
+									"Intermediate buffer length is less than transform function accepts to process"
+								));
+							};
+						}
+					}
+					continue;
+				}
+				TransformOutcome::Output(output, processed) => {
+					self.buffer[..].rotate_left(processed);
 					self.read -= processed;
 					self.position = self.position.wrapping_add(processed);
-					continue;
-				};
-				let len = min(output.len(), buffer.len());
-				buffer[..len].copy_from_slice(&output[..len]);
-				output.drain(..len);
-				self.residue = output;
-				self.buffer[..].rotate_left(processed);
-				self.read -= processed;
-				self.position = self.position.wrapping_add(processed);
-				return Ok(len);
+					if output.is_empty() {
+						continue;
+					};
+					self.residue = output;
+					return Ok(());
+				}
 			}
 		}
 	}
 }
 
+impl<T: Read> Read for ReadTransformer<T> {
+	fn read(&mut self, buffer: &mut [u8]) -> IOResult<usize> {
+		if self.residue.is_empty() {
+			self.fill_residue()?;
+		};
+		let len = min(self.residue.len(), buffer.len());
+		buffer[..len].copy_from_slice(&self.residue[..len]);
+		self.residue.drain(..len);
+		Ok(len)
+	}
+}
+
+/// Exposes the transformed stream through `fill_buf`/`consume`, so callers can use `read_line`,
+/// `lines`, `split`, etc. directly over the transformed output instead of copying through their
+/// own buffer.
+#[cfg(feature = "std")]
+impl<T: Read> BufRead for ReadTransformer<T> {
+	fn fill_buf(&mut self) -> IOResult<&[u8]> {
+		if self.residue.is_empty() {
+			self.fill_residue()?;
+		};
+		Ok(&self.residue)
+	}
+
+	fn consume(&mut self, amt: usize) {
+		// Mirror std's own `BufRead` implementations (e.g. `BufReader`): an `amt` larger than
+		// what `fill_buf` returned is a logic error on the caller's part, but it must be clamped
+		// rather than allowed to panic.
+		let amt = min(amt, self.residue.len());
+		self.residue.drain(..amt);
+	}
+}
+
+/// Seeks the underlying `Read + Seek` object and re-primes the transformer at the new offset.
+///
+/// Only usable on a `ReadTransformer` built with [`ReadTransformer::new_seekable`] — the
+/// transform function must be length-preserving, so an output offset maps 1:1 onto an input
+/// offset. Seeking on a transformer built with [`ReadTransformer::new`] returns an error.
+#[cfg(feature = "std")]
+impl<T: Read + Seek> Seek for ReadTransformer<T> {
+	fn seek(&mut self, pos: SeekFrom) -> IOResult<u64> {
+		if !self.seekable {
+			return Err(IOError::new(
+				IOErrorKind::Other,
+				"ReadTransformer is not seekable; construct it with ReadTransformer::new_seekable for a length-preserving transform"
+			));
+		}
+		// `self.position` tracks bytes already handed to the transform, but `self.residue` holds
+		// already-transformed output not yet delivered to the caller, so the logical (delivered)
+		// position lags `self.position` by `self.residue.len()`. `SeekFrom::Current` is relative
+		// to that logical position, not to the underlying `input`'s physical cursor (which sits
+		// further ahead by whatever is buffered), so it has to be resolved to an absolute offset
+		// before reaching `input`.
+		let target = match pos {
+			SeekFrom::Start(_) | SeekFrom::End(_) => pos,
+			SeekFrom::Current(offset) => {
+				let logical = self.position as i64 - self.residue.len() as i64;
+				let absolute = logical.checked_add(offset).ok_or_else(|| {
+					IOError::new(IOErrorKind::InvalidInput, "seek position overflow")
+				})?;
+				if absolute < 0 {
+					return Err(IOError::new(
+						IOErrorKind::InvalidInput,
+						"invalid seek to a negative position",
+					));
+				};
+				SeekFrom::Start(absolute as u64)
+			}
+		};
+		let new_position = self.input.seek(target)?;
+		self.position = new_position as usize;
+		self.read = 0;
+		self.residue.clear();
+		Ok(new_position)
+	}
+}
+
 /// Convenience trait which implemented by all `Read` objects. Allows chaining of `Read` objects.
 ///
 /// # Example
 /// ```ignore
 /// let mut data = Cursor::new(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]).transform(
 /// 	5,
-/// 	Box::new(|buffer: &mut [u8], _position, _last_attempt| -> Option<(Vec<u8>, usize)> {
+/// 	Box::new(|buffer: &mut [u8], _position, _last_attempt| -> TransformOutcome {
 /// 		let buf = buffer
 /// 			.iter()
 /// 			.filter(|x| {
@@ -143,7 +301,7 @@ impl<T: Read> Read for ReadTransformer<T> {
 /// 			})
 /// 			.cloned()
 /// 			.collect::<Vec<_>>();
-/// 		return Some((buf, buffer.len()));
+/// 		return TransformOutcome::Output(buf, buffer.len());
 /// 	}),
 /// );
 /// let mut out = vec![0; 5];
@@ -164,10 +322,161 @@ impl<T: Read> TransformableRead<T> for T {
 	}
 }
 
-#[cfg(test)]
+/// Transforms `Write` object with function. Mirrors `ReadTransformer` for the write side of a
+/// pipeline: bytes passed to `write` are buffered, handed to the transform function, and the
+/// processed output is forwarded to the wrapped sink.
+///
+/// # Example
+/// ```ignore
+/// let mut out = vec![];
+/// {
+/// 	let mut transformed = WriteTransformer::new(
+/// 		&mut out,
+/// 		5,
+/// 		Box::new(|buffer: &mut [u8], _position, _last_attempt| -> TransformOutcome {
+/// 			return TransformOutcome::Output(
+/// 				buffer
+/// 					.iter()
+/// 					.map(|x| {
+/// 						if x % 2 == 0 {
+/// 							return 0;
+/// 						};
+/// 						return *x;
+/// 					})
+/// 					.collect::<Vec<_>>(),
+/// 				buffer.len(),
+/// 			);
+/// 		}),
+/// 	);
+/// 	transformed.write_all(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]).unwrap();
+/// 	transformed.flush().unwrap();
+/// }
+/// assert_eq!(out, [1, 0, 3, 0, 5, 0, 7, 0, 9, 0]);
+/// ```
+pub struct WriteTransformer<T: Write> {
+	output: T,
+	buffer: Vec<u8>,
+	written: usize,
+	position: usize,
+	transform: TransformFn,
+}
+
+impl<T: Write> WriteTransformer<T> {
+	/// Creates new `WriteTransformer`
+	///
+	/// # Params
+	/// * `output` - output which will receive processed bytes
+	/// * `size` - size of intermediate buffer
+	/// * `transform_fn` - boxed function which acts like a map function.
+	pub fn new(output: T, size: usize, transform_fn: TransformFn) -> Self {
+		Self {
+			output,
+			buffer: vec![0; size],
+			written: 0,
+			position: 0,
+			transform: transform_fn,
+		}
+	}
+}
+
+impl<T: Write> Write for WriteTransformer<T> {
+	fn write(&mut self, buffer: &[u8]) -> IOResult<usize> {
+		let len = min(buffer.len(), self.buffer.len() - self.written);
+		self.buffer[self.written..self.written + len].copy_from_slice(&buffer[..len]);
+		self.written += len;
+		loop {
+			let res = (self.transform)(&mut self.buffer[..self.written], self.position, false);
+			match res {
+				TransformOutcome::Output(output, processed) => {
+					self.output.write_all(&output)?;
+					self.buffer[..].rotate_left(processed);
+					self.written -= processed;
+					self.position = self.position.wrapping_add(processed);
+					return Ok(len);
+				}
+				TransformOutcome::NeedMore(requested) => match requested {
+					Some(requested) if requested > self.buffer.len() => {
+						let grown = requested.max(self.buffer.len() * 2);
+						self.buffer.resize(grown, 0);
+						continue;
+					}
+					_ => {
+						if self.written == self.buffer.len() {
+							return Err(IOError::new(
+								IOErrorKind::Other,
+								"Intermediate buffer length is less than transform function accepts to process"
+							));
+						};
+						return Ok(len);
+					}
+				},
+			}
+		}
+	}
+
+	fn flush(&mut self) -> IOResult<()> {
+		if self.written > 0 {
+			match (self.transform)(&mut self.buffer[..self.written], self.position, true) {
+				TransformOutcome::Output(output, processed) => {
+					self.output.write_all(&output)?;
+					self.buffer[..].rotate_left(processed);
+					self.written -= processed;
+					self.position = self.position.wrapping_add(processed);
+				}
+				TransformOutcome::NeedMore(_) => {
+					return Err(IOError::new(
+						IOErrorKind::Other,
+						"EOF reached and the length of the buffer is less than transform function accepts to process"
+					));
+				}
+			}
+		};
+		self.output.flush()
+	}
+}
+
+/// Convenience trait which implemented by all `Write` objects. Allows chaining of `Write` objects.
+///
+/// # Example
+/// ```ignore
+/// let mut out = vec![];
+/// {
+/// 	let mut transformed = (&mut out).transform(
+/// 		5,
+/// 		Box::new(|buffer: &mut [u8], _position, _last_attempt| -> TransformOutcome {
+/// 			let buf = buffer
+/// 				.iter()
+/// 				.filter(|x| {
+/// 					return *x % 2 == 0;
+/// 				})
+/// 				.cloned()
+/// 				.collect::<Vec<_>>();
+/// 			return TransformOutcome::Output(buf, buffer.len());
+/// 		}),
+/// 	);
+/// 	transformed.write_all(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]).unwrap();
+/// 	transformed.flush().unwrap();
+/// }
+/// assert_eq!(out, [2, 4, 6, 8, 10]);
+/// ```
+pub trait TransformableWrite<T: Write>: Write {
+	fn transform(self, buffer_size: usize, transform_fn: TransformFn) -> WriteTransformer<T>;
+	fn transform_by_tuple(self, (usize, TransformFn)) -> WriteTransformer<T>;
+}
+
+impl<T: Write> TransformableWrite<T> for T {
+	fn transform(self, buffer_size: usize, transform_fn: TransformFn) -> WriteTransformer<T> {
+		WriteTransformer::new(self, buffer_size, transform_fn)
+	}
+	fn transform_by_tuple(self, tuple: (usize, TransformFn)) -> WriteTransformer<T> {
+		WriteTransformer::new(self, tuple.0, tuple.1)
+	}
+}
+
+#[cfg(all(test, feature = "std"))]
 mod read_transformer_tests {
-	use super::{ReadTransformer, TransformableRead};
-	use std::io::{Cursor, Read};
+	use super::{ReadTransformer, TransformableRead, TransformOutcome};
+	use std::io::{BufRead, Cursor, Read, Seek, SeekFrom};
 
 	#[test]
 	fn even_zeroed_test() {
@@ -175,8 +484,8 @@ mod read_transformer_tests {
 		let mut transformed = ReadTransformer::new(
 			&mut data,
 			5,
-			Box::new(|buffer: &mut [u8], _, _| -> Option<(Vec<u8>, usize)> {
-				return Some((
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+				return TransformOutcome::Output(
 					buffer
 						.iter()
 						.map(|x| {
@@ -186,7 +495,7 @@ mod read_transformer_tests {
 							return *x;
 						}).collect::<Vec<_>>(),
 					buffer.len(),
-				));
+				);
 			}),
 		);
 		let mut out = vec![0; 10];
@@ -200,14 +509,14 @@ mod read_transformer_tests {
 		let mut transformed = ReadTransformer::new(
 			&mut data,
 			5,
-			Box::new(|buffer: &mut [u8], _, _| -> Option<(Vec<u8>, usize)> {
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
 				let buf = buffer
 					.iter()
 					.filter(|x| {
 						return *x % 2 == 0;
 					}).cloned()
 					.collect::<Vec<_>>();
-				return Some((buf, buffer.len()));
+				return TransformOutcome::Output(buf, buffer.len());
 			}),
 		);
 		let mut out = vec![0; 5];
@@ -221,13 +530,13 @@ mod read_transformer_tests {
 		let mut transformed = ReadTransformer::new(
 			&mut data,
 			6,
-			Box::new(|buffer: &mut [u8], _, _| -> Option<(Vec<u8>, usize)> {
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
 				if buffer.len() < 4 {
-					return None;
+					return TransformOutcome::NeedMore(None);
 				}
 				let mut out = buffer[..4].to_vec().clone();
 				out.reverse();
-				return Some((out, 4));
+				return TransformOutcome::Output(out, 4);
 			}),
 		);
 		let mut out = vec![0; 8];
@@ -235,31 +544,167 @@ mod read_transformer_tests {
 		assert_eq!(out, [4, 3, 2, 1, 8, 7, 6, 5]);
 	}
 
+	#[test]
+	fn grows_buffer_test() {
+		// length-prefixed framing: the first byte is the frame length, the rest is the frame.
+		// The intermediate buffer starts too small to hold a frame, so the transform has to
+		// request a bigger one via `TransformOutcome::NeedMore(Some(len))`.
+		let mut data = Cursor::new(vec![5, 1, 2, 3, 4, 5]);
+		let mut transformed = ReadTransformer::new(
+			&mut data,
+			2,
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+				if buffer.is_empty() {
+					return TransformOutcome::NeedMore(None);
+				}
+				let needed = 1 + buffer[0] as usize;
+				if buffer.len() < needed {
+					return TransformOutcome::NeedMore(Some(needed));
+				}
+				return TransformOutcome::Output(buffer[1..needed].to_vec(), needed);
+			}),
+		);
+		let mut out = vec![0; 5];
+		transformed.read_exact(&mut out).unwrap();
+		assert_eq!(out, [1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn seekable_test() {
+		let mut data = Cursor::new(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+		let mut transformed = ReadTransformer::new_seekable(
+			&mut data,
+			4,
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+				return TransformOutcome::Output(
+					buffer
+						.iter()
+						.map(|x| {
+							if x % 2 == 0 {
+								return 0;
+							};
+							return *x;
+						}).collect::<Vec<_>>(),
+					buffer.len(),
+				);
+			}),
+		);
+		let mut out = vec![0; 3];
+		transformed.read_exact(&mut out).unwrap();
+		assert_eq!(out, [1, 0, 3]);
+
+		transformed.seek(SeekFrom::Start(5)).unwrap();
+		let mut out = vec![0; 5];
+		transformed.read_exact(&mut out).unwrap();
+		assert_eq!(out, [0, 7, 0, 9, 0]);
+	}
+
+	#[test]
+	fn seek_current_respects_logical_position_test() {
+		let mut data = Cursor::new(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+		let mut transformed = ReadTransformer::new_seekable(
+			&mut data,
+			4,
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+				return TransformOutcome::Output(
+					buffer
+						.iter()
+						.map(|x| {
+							if x % 2 == 0 {
+								return 0;
+							};
+							return *x;
+						}).collect::<Vec<_>>(),
+					buffer.len(),
+				);
+			}),
+		);
+		let mut out = vec![0; 3];
+		transformed.read_exact(&mut out).unwrap();
+		assert_eq!(out, [1, 0, 3]);
+
+		// one transformed byte is still buffered in `residue` here; `Current(0)` must report the
+		// logical (delivered) position, not the underlying reader's physical lookahead position.
+		let position = transformed.seek(SeekFrom::Current(0)).unwrap();
+		assert_eq!(position, 3);
+
+		let mut out = vec![0; 7];
+		transformed.read_exact(&mut out).unwrap();
+		assert_eq!(out, [0, 5, 0, 7, 0, 9, 0]);
+	}
+
+	#[test]
+	fn not_seekable_test() {
+		let mut data = Cursor::new(vec![1, 2, 3, 4, 5]);
+		let mut transformed = ReadTransformer::new(
+			&mut data,
+			4,
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+				return TransformOutcome::Output(buffer.to_vec(), buffer.len());
+			}),
+		);
+		assert!(transformed.seek(SeekFrom::Start(0)).is_err());
+	}
+
+	#[test]
+	fn buf_read_test() {
+		let mut data = Cursor::new(b"abc\ndef\n".to_vec());
+		let mut transformed = ReadTransformer::new(
+			&mut data,
+			3,
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+				return TransformOutcome::Output(buffer.to_vec(), buffer.len());
+			}),
+		);
+		let mut line = String::new();
+		transformed.read_line(&mut line).unwrap();
+		assert_eq!(line, "abc\n");
+		line.clear();
+		transformed.read_line(&mut line).unwrap();
+		assert_eq!(line, "def\n");
+	}
+
+	#[test]
+	fn consume_clamps_overlarge_amt_test() {
+		let mut data = Cursor::new(vec![1, 2, 3]);
+		let mut transformed = ReadTransformer::new(
+			&mut data,
+			3,
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+				return TransformOutcome::Output(buffer.to_vec(), buffer.len());
+			}),
+		);
+		let len = transformed.fill_buf().unwrap().len();
+		// an `amt` larger than what `fill_buf` returned is caller misuse; it must be clamped
+		// rather than panicking, matching std's own `BufRead` implementations.
+		transformed.consume(len + 10);
+	}
+
 	#[test]
 	fn combined_test() {
 		let mut data = Cursor::new(vec![1, 2, 3, 4, 5, 6, 7, 8]);
 		let mut transformed = ReadTransformer::new(
 			&mut data,
 			4,
-			Box::new(|buffer: &mut [u8], _, _| -> Option<(Vec<u8>, usize)> {
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
 				if buffer.len() < 4 {
-					return None;
+					return TransformOutcome::NeedMore(None);
 				}
 				let mut out = buffer.to_vec().clone();
 				out.reverse();
-				return Some((out, 4));
+				return TransformOutcome::Output(out, 4);
 			}),
 		);
 		let mut transformed = ReadTransformer::new(
 			&mut transformed,
 			2,
-			Box::new(|buffer: &mut [u8], _, _| -> Option<(Vec<u8>, usize)> {
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
 				if buffer.len() < 2 {
-					return None;
+					return TransformOutcome::NeedMore(None);
 				}
 				let mut out = buffer.to_vec().clone();
 				out.reverse();
-				return Some((out, 2));
+				return TransformOutcome::Output(out, 2);
 			}),
 		);
 		let mut out = vec![0; 8];
@@ -274,7 +719,7 @@ mod read_transformer_tests {
 		let mut transformed = ReadTransformer::new(
 			&mut data,
 			4,
-			Box::new(move |buffer: &mut [u8], _, _| -> Option<(Vec<u8>, usize)> {
+			Box::new(move |buffer: &mut [u8], _, _| -> TransformOutcome {
 				let out = buffer
 					.to_vec()
 					.iter()
@@ -283,7 +728,7 @@ mod read_transformer_tests {
 						i += 1;
 						return x;
 					}).collect::<Vec<_>>();
-				return Some((out, buffer.len()));
+				return TransformOutcome::Output(out, buffer.len());
 			}),
 		);
 		let mut out = vec![0; 8];
@@ -295,14 +740,14 @@ mod read_transformer_tests {
 	fn transformable_read_test() {
 		let mut data = Cursor::new(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]).transform(
 			5,
-			Box::new(|buffer: &mut [u8], _, _| -> Option<(Vec<u8>, usize)> {
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
 				let buf = buffer
 					.iter()
 					.filter(|x| {
 						return *x % 2 == 0;
 					}).cloned()
 					.collect::<Vec<_>>();
-				return Some((buf, buffer.len()));
+				return TransformOutcome::Output(buf, buffer.len());
 			}),
 		);
 		let mut out = vec![0; 5];
@@ -315,14 +760,14 @@ mod read_transformer_tests {
 		let mut data = Cursor::new(vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
 		let mut transformed = (&mut data).transform(
 			5,
-			Box::new(|buffer: &mut [u8], _, _| -> Option<(Vec<u8>, usize)> {
+			Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
 				let buf = buffer
 					.iter()
 					.filter(|x| {
 						return *x % 2 == 0;
 					}).cloned()
 					.collect::<Vec<_>>();
-				return Some((buf, buffer.len()));
+				return TransformOutcome::Output(buf, buffer.len());
 			}),
 		);
 		let mut out = vec![0; 5];
@@ -330,3 +775,133 @@ mod read_transformer_tests {
 		assert_eq!(out, [2, 4, 6, 8, 10]);
 	}
 }
+
+#[cfg(all(test, feature = "std"))]
+mod write_transformer_tests {
+	use super::{TransformableWrite, WriteTransformer, TransformOutcome};
+	use std::io::Write;
+
+	#[test]
+	fn even_zeroed_test() {
+		let mut out = vec![];
+		{
+			let mut transformed = WriteTransformer::new(
+				&mut out,
+				5,
+				Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+					return TransformOutcome::Output(
+						buffer
+							.iter()
+							.map(|x| {
+								if x % 2 == 0 {
+									return 0;
+								};
+								return *x;
+							}).collect::<Vec<_>>(),
+						buffer.len(),
+					);
+				}),
+			);
+			transformed.write_all(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]).unwrap();
+			transformed.flush().unwrap();
+		}
+		assert_eq!(out, [1, 0, 3, 0, 5, 0, 7, 0, 9, 0]);
+	}
+
+	#[test]
+	fn filter_test() {
+		let mut out = vec![];
+		{
+			let mut transformed = WriteTransformer::new(
+				&mut out,
+				5,
+				Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+					let buf = buffer
+						.iter()
+						.filter(|x| {
+							return *x % 2 == 0;
+						}).cloned()
+						.collect::<Vec<_>>();
+					return TransformOutcome::Output(buf, buffer.len());
+				}),
+			);
+			transformed.write_all(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]).unwrap();
+			transformed.flush().unwrap();
+		}
+		assert_eq!(out, [2, 4, 6, 8, 10]);
+	}
+
+	#[test]
+	fn flush_errors_on_incomplete_residue_test() {
+		let mut out = vec![];
+		{
+			let mut transformed = WriteTransformer::new(
+				&mut out,
+				6,
+				Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+					if buffer.len() < 4 {
+						return TransformOutcome::NeedMore(None);
+					}
+					let mut out = buffer[..4].to_vec().clone();
+					out.reverse();
+					return TransformOutcome::Output(out, 4);
+				}),
+			);
+			transformed.write_all(&[1, 2, 3, 4, 5, 6]).unwrap();
+			// the trailing [5, 6] can never complete a 4-byte frame, so flush must surface an
+			// error instead of silently dropping them.
+			assert!(transformed.flush().is_err());
+		}
+		assert_eq!(out, [4, 3, 2, 1]);
+	}
+
+	#[test]
+	fn grows_buffer_test() {
+		// length-prefixed framing, fed one byte at a time through an initial buffer that is too
+		// small to hold a whole frame, forcing `WriteTransformer` to grow it mid-write.
+		let mut out = vec![];
+		{
+			let mut transformed = WriteTransformer::new(
+				&mut out,
+				2,
+				Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+					if buffer.is_empty() {
+						return TransformOutcome::NeedMore(None);
+					}
+					let needed = 1 + buffer[0] as usize;
+					if buffer.len() < needed {
+						return TransformOutcome::NeedMore(Some(needed));
+					}
+					return TransformOutcome::Output(buffer[1..needed].to_vec(), needed);
+				}),
+			);
+			for byte in &[5, 1, 2, 3, 4, 5] {
+				transformed.write_all(&[*byte]).unwrap();
+			}
+			transformed.flush().unwrap();
+		}
+		assert_eq!(out, [1, 2, 3, 4, 5]);
+	}
+
+	#[test]
+	fn transformable_write_test() {
+		let mut out = vec![];
+		{
+			let mut transformed = (&mut out).transform(
+				5,
+				Box::new(|buffer: &mut [u8], _, _| -> TransformOutcome {
+					let buf = buffer
+						.iter()
+						.filter(|x| {
+							return *x % 2 == 0;
+						}).cloned()
+						.collect::<Vec<_>>();
+					return TransformOutcome::Output(buf, buffer.len());
+				}),
+			);
+			transformed.write_all(&[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]).unwrap();
+			transformed.flush().unwrap();
+		}
+		assert_eq!(out, [2, 4, 6, 8, 10]);
+	}
+}